@@ -1,8 +1,103 @@
+/// A typical CPU cache-line size in bytes, and the alignment guaranteed by
+/// Arrow's `MutableBuffer`. A convenient default for
+/// [`RawMemoryRef::to_aligned_vec`].
+pub const CACHE_LINE_ALIGN: usize = 64;
+
+/// A single step in a declarative pointer-chasing path, used by
+/// [`RawMemoryRef::follow`] to describe arbitrary nested memory layouts.
+///
+/// Each step acts relative to a "current base pointer" cursor: [`Step::Field`]
+/// and [`Step::Deref`] move it, while [`Step::LenFromWord`] and
+/// [`Step::CapFromWord`] read relative to it without moving it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// Shift the current base pointer by `offset_in_words` words
+    /// (`usize`-sized), without dereferencing. Useful for stepping to a
+    /// struct field ahead of a [`Step::Deref`].
+    Field(usize),
+    /// Read the `usize` at the current base pointer and follow it: the value
+    /// read becomes the new current base pointer.
+    Deref,
+    /// Read the `usize` at `word_index` words past the current base pointer
+    /// and record it as the pending length, in elements.
+    LenFromWord(usize),
+    /// Read the `usize` at `word_index` words past the current base pointer
+    /// and record it as the pending capacity, in elements.
+    CapFromWord(usize),
+}
+
+/// Word indices (from the start of a `Vec`-shaped value) at which the raw
+/// data pointer, capacity, and length fields actually live, as determined by
+/// [`vec_word_order`].
+struct VecWordOrder {
+    ptr_idx: usize,
+    cap_idx: usize,
+    len_idx: usize,
+}
+
+/// Determines the real in-memory word order of a `Vec`'s pointer, capacity,
+/// and length fields, for use by
+/// [`into_inner_with_length_and_capacity`][RawMemoryRef::into_inner_with_length_and_capacity].
+///
+/// `Vec` is not `#[repr(C)]`, so the compiler is free to lay these three
+/// words out in whatever order it likes, and that order has been observed to
+/// differ across toolchains (`[ptr, cap, len]` on some, `[cap, ptr, len]` on
+/// others). Rather than hardcode one order and silently read garbage (or
+/// segfault) on a toolchain where it doesn't hold, this is determined once,
+/// lazily, by building a throwaway `Vec<u8>` with a distinguishable capacity
+/// and length and checking which of its first three words holds which value.
+fn vec_word_order() -> &'static VecWordOrder {
+    static ORDER: std::sync::OnceLock<VecWordOrder> = std::sync::OnceLock::new();
+    ORDER.get_or_init(|| {
+        let mut probe = Vec::with_capacity(11);
+        probe.extend_from_slice(&[0u8; 3]);
+
+        let probe_ptr = probe.as_ptr() as usize;
+        let probe_cap = probe.capacity();
+        let probe_len = probe.len();
+
+        let words =
+            unsafe { std::slice::from_raw_parts(&probe as *const Vec<u8> as *const usize, 3) };
+
+        let ptr_idx = words
+            .iter()
+            .position(|&w| w == probe_ptr)
+            .expect("Vec's first three words don't contain its data pointer on this toolchain");
+        let cap_idx = words
+            .iter()
+            .position(|&w| w == probe_cap)
+            .expect("Vec's first three words don't contain its capacity on this toolchain");
+        let len_idx = words
+            .iter()
+            .position(|&w| w == probe_len)
+            .expect("Vec's first three words don't contain its length on this toolchain");
+
+        assert!(
+            ptr_idx != cap_idx && ptr_idx != len_idx && cap_idx != len_idx,
+            "Vec's pointer, capacity, and length words were not all distinguishable on this toolchain"
+        );
+
+        VecWordOrder {
+            ptr_idx,
+            cap_idx,
+            len_idx,
+        }
+    })
+}
+
 /// RawMemoryRef holds onto a raw pointer for the purpose of reading its raw bytes of memory.
 pub struct RawMemoryRef<'a> {
     inner: *const usize,
+    /// Byte offset from `inner` at which this view's `[0, capacity)` window
+    /// actually begins. Lets [`slice`][RawMemoryRef::slice] and friends carve
+    /// out sub-views without losing track of the original base pointer.
+    offset: usize,
     length: usize,
     capacity: usize,
+    /// Invariant: `length <= num_initialized <= capacity`. Bytes in
+    /// `[0, num_initialized)` are known to be initialized; bytes in
+    /// `[num_initialized, capacity)` may not be.
+    num_initialized: usize,
     phantom: std::marker::PhantomData<&'a ()>,
 }
 
@@ -12,8 +107,10 @@ impl<'a> RawMemoryRef<'a> {
         let length = std::mem::size_of_val(inner);
         Self {
             inner: inner as *const _ as *const usize,
+            offset: 0,
             length,
             capacity: length,
+            num_initialized: length,
             phantom: std::marker::PhantomData,
         }
     }
@@ -28,35 +125,32 @@ impl<'a> RawMemoryRef<'a> {
     /// Most likely, you want to use [`new`][RawMemoryRef::new] which can detect
     /// the allocated size of the data referenced.
     pub unsafe fn with_capacity<T>(inner: &'a T, capacity: usize) -> Self {
+        let length = std::mem::size_of_val(inner);
         Self {
             inner: inner as *const _ as *const usize,
-            length: std::mem::size_of_val(inner),
+            offset: 0,
+            length,
             capacity,
+            // The caller only vouches for `length`, not the rest of
+            // `capacity`, so assume nothing further is initialized.
+            num_initialized: length,
             phantom: std::marker::PhantomData,
         }
     }
 
+    /// Equivalent to [`follow`][RawMemoryRef::follow] with `&[Step::Deref]`: follows
+    /// the pointer stored at the current location and treats the pointee as
+    /// `inner_size_bytes` bytes of data.
     pub unsafe fn into_inner(&self, inner_size_bytes: usize) -> Self {
-        Self {
-            inner: *self.inner as *const usize,
-            length: inner_size_bytes,
-            capacity: inner_size_bytes,
-            phantom: std::marker::PhantomData,
-        }
+        self.follow(&[Step::Deref], inner_size_bytes)
     }
 
+    /// Equivalent to [`follow`][RawMemoryRef::follow] with
+    /// `&[Step::LenFromWord(1), Step::Deref]`: reads a length from the word
+    /// after the current location, then follows the pointer stored at the
+    /// current location.
     pub unsafe fn into_inner_with_length(&self, inner_size_bytes: usize) -> Self {
-        let usizes = std::slice::from_raw_parts(self.inner, 2);
-        let inner_size = usizes
-            .get(1)
-            .expect("pointer type given should contain a length");
-
-        Self {
-            inner: *self.inner as *const usize,
-            length: *inner_size * inner_size_bytes,
-            capacity: *inner_size * inner_size_bytes,
-            phantom: std::marker::PhantomData,
-        }
+        self.follow(&[Step::LenFromWord(1), Step::Deref], inner_size_bytes)
     }
 
     /// Returns a new RawMemoryRef of contained pointer, capacity, and length values.
@@ -96,33 +190,116 @@ impl<'a> RawMemoryRef<'a> {
     /// assert_eq!(bytes.len(), 3);
     /// ```
     ///
-    /// Worth noting that capacity is ususally in the same slot as length for types where
-    /// there is no stored capacity. This means that
-    /// [`into_inner_with_length`][RawMemoryRef::into_inner_with_length]
-    /// should work in place of
-    /// [`into_inner_with_length_and_capacity`][RawMemoryRef::into_inner_with_length_and_capacity]
-    /// if there isn't a need for skipping unintialized bytes.
+    /// Worth noting that [`into_inner_with_length`][RawMemoryRef::into_inner_with_length]
+    /// only works in place of this method for genuinely two-word (pointer,
+    /// length) types with no stored capacity at all, such as `Box<[T]>`. It
+    /// is not a substitute for `Vec`, whose three words include a capacity
+    /// that may or may not sit next to the pointer.
+    ///
+    /// Unlike [`follow`][RawMemoryRef::follow], which takes the path's word
+    /// indices literally, this method does not hardcode which word holds the
+    /// capacity and which holds the length: `Vec` is not `#[repr(C)]`, so the
+    /// compiler is free to order those words however it likes, and that
+    /// order is known to differ across toolchains. The real order is
+    /// determined once via [`vec_word_order`] and used to build the
+    /// equivalent [`follow`][RawMemoryRef::follow] path.
     pub unsafe fn into_inner_with_length_and_capacity(&self, inner_size_bytes: usize) -> Self {
-        let usizes = std::slice::from_raw_parts(self.inner, 3);
-        let inner_capacity = usizes
-            .get(1)
-            .expect("pointer type given should contain a capacity");
-        let inner_size = usizes
-            .get(2)
-            .expect("pointer type given should contain a length");
+        let order = vec_word_order();
+
+        let mut path = vec![Step::CapFromWord(order.cap_idx), Step::LenFromWord(order.len_idx)];
+        if order.ptr_idx != 0 {
+            path.push(Step::Field(order.ptr_idx));
+        }
+        path.push(Step::Deref);
+
+        self.follow(&path, inner_size_bytes)
+    }
+
+    /// Walks a declarative path of [`Step`]s over raw memory, starting from
+    /// this view's current location, to build an arbitrary nested
+    /// [`RawMemoryRef`].
+    ///
+    /// This generalizes the [`into_inner`][RawMemoryRef::into_inner] family:
+    /// each step either moves the current base pointer ([`Step::Field`],
+    /// [`Step::Deref`]) or records a pending length/capacity in elements
+    /// ([`Step::LenFromWord`], [`Step::CapFromWord`]) read relative to the
+    /// current base pointer, without moving it. Once every step has run, the
+    /// pending length/capacity (in elements; the length defaults to `1` if
+    /// never set, and the capacity defaults to the length) are multiplied by
+    /// `elem_size` to produce the final byte-based `length`/`capacity`.
+    ///
+    /// This lets you describe arbitrary nested layouts declaratively, such as
+    /// a struct field that is itself a boxed slice
+    /// (`&[Step::Field(offset_of_field_in_words), Step::LenFromWord(1), Step::Deref]`).
+    /// Note that word indices like these are only safe to hardcode for types
+    /// with a known, fixed layout (e.g. a two-word pointer-and-length fat
+    /// pointer such as `Box<[T]>`); `Vec`'s word order isn't guaranteed, which
+    /// is why [`into_inner_with_length_and_capacity`][RawMemoryRef::into_inner_with_length_and_capacity]
+    /// determines it at runtime via [`vec_word_order`] rather than hardcoding it here.
+    /// ```
+    /// let value: Box<[u8]> = vec![1u8, 2, 3].into_boxed_slice();
+    /// let dataref = unsafe {
+    ///     raw_memory_ref::RawMemoryRef::new(&value).follow(
+    ///         &[
+    ///             raw_memory_ref::Step::LenFromWord(1),
+    ///             raw_memory_ref::Step::Deref,
+    ///         ],
+    ///         std::mem::size_of::<u8>(),
+    ///     )
+    /// };
+    ///
+    /// assert_eq!(dataref.initialized_bytes(), [1u8, 2, 3]);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Every [`Step::Deref`], [`Step::LenFromWord`], and [`Step::CapFromWord`]
+    /// reads a `usize` through the current base pointer. The caller must
+    /// guarantee that, at each such step, the current base pointer is valid
+    /// for a `usize`-sized, properly aligned read, just as with the
+    /// `into_inner_*` family this replaces.
+    pub unsafe fn follow(&self, path: &[Step], elem_size: usize) -> Self {
+        const WORD_SIZE: usize = std::mem::size_of::<usize>();
+
+        let mut base = self.byte_ptr();
+        let mut len_elems: Option<usize> = None;
+        let mut cap_elems: Option<usize> = None;
+
+        for step in path {
+            match *step {
+                Step::Field(offset_in_words) => {
+                    base = base.add(offset_in_words * WORD_SIZE);
+                }
+                Step::Deref => {
+                    base = *(base as *const usize) as *const u8;
+                }
+                Step::LenFromWord(word_index) => {
+                    len_elems = Some(*(base.add(word_index * WORD_SIZE) as *const usize));
+                }
+                Step::CapFromWord(word_index) => {
+                    cap_elems = Some(*(base.add(word_index * WORD_SIZE) as *const usize));
+                }
+            }
+        }
+
+        let length = len_elems.unwrap_or(1) * elem_size;
+        let capacity = cap_elems.unwrap_or_else(|| len_elems.unwrap_or(1)) * elem_size;
 
         Self {
-            inner: *self.inner as *const usize,
-            length: *inner_size * inner_size_bytes,
-            capacity: *inner_capacity * inner_size_bytes,
+            inner: base as *const usize,
+            offset: 0,
+            length,
+            capacity,
+            // The region between `length` and `capacity` is not guaranteed
+            // to be initialized unless the path only ever produced an equal
+            // length and capacity.
+            num_initialized: length,
             phantom: std::marker::PhantomData,
         }
     }
 
-    /// Returns a slice of bytes of the referenced data. The bytes may
-    /// or may not be initialized bytes.
-    ///
-    /// Refer to [`initialized_bytes`][RawMemoryRef::initialized_bytes] if you want to skip uninitialized tail bytes.
+    /// Returns a slice of bytes covering the entire allocated capacity,
+    /// including any bytes that may still be uninitialized.
     ///
     /// For example, a [`Vec`] might have a larger capacity than actual size
     /// which would contain uninitialized bytes.
@@ -136,13 +313,65 @@ impl<'a> RawMemoryRef<'a> {
     ///     raw_memory_ref::RawMemoryRef::new(&numbers)
     ///         .into_inner_with_length_and_capacity(std::mem::size_of::<u8>())
     /// };
-    /// let bytes = dataref.allocated_bytes();
+    /// let bytes = unsafe { dataref.assume_all_init() };
     ///
     /// assert_eq!(bytes[..], [1u8, 2, 3, 0, 0]);
     /// assert_eq!(bytes.len(), 5);
     /// ```
-    pub fn allocated_bytes(&self) -> &'a [u8] {
-        unsafe { std::slice::from_raw_parts(self.inner as *const u8, self.capacity) }
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that every byte in `[0, capacity())` is
+    /// initialized. The region between [`len`][RawMemoryRef::len] and
+    /// [`capacity`][RawMemoryRef::capacity] (e.g. a [`Vec`]'s spare capacity)
+    /// is not assumed to be initialized unless [`assume_init_spare`][RawMemoryRef::assume_init_spare]
+    /// has been used to extend that guarantee first. Prefer
+    /// [`initialized_bytes`][RawMemoryRef::initialized_bytes] for the
+    /// known-initialized region, or [`spare_capacity`][RawMemoryRef::spare_capacity]
+    /// to inspect the rest as [`MaybeUninit<u8>`][std::mem::MaybeUninit], if you
+    /// can't make that guarantee.
+    pub unsafe fn assume_all_init(&self) -> &'a [u8] {
+        std::slice::from_raw_parts(self.byte_ptr(), self.capacity)
+    }
+
+    /// Returns the spare capacity of the referenced data as a slice of
+    /// [`MaybeUninit<u8>`][std::mem::MaybeUninit], covering `[num_initialized, capacity)`.
+    ///
+    /// This is the sound way to look at bytes past the known-initialized
+    /// region, for example to inspect a [`Vec`]'s spare capacity without
+    /// forming a `&[u8]` over memory that may be uninitialized.
+    pub fn spare_capacity(&self) -> &'a [std::mem::MaybeUninit<u8>] {
+        unsafe {
+            std::slice::from_raw_parts(
+                self.byte_ptr().add(self.num_initialized) as *const std::mem::MaybeUninit<u8>,
+                self.capacity - self.num_initialized,
+            )
+        }
+    }
+
+    /// Advances the known-initialized cursor by `n` bytes into the spare
+    /// capacity, returning a new [`RawMemoryRef`] that reflects the wider
+    /// initialized region.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that the next `n` bytes past
+    /// [`num_initialized`][RawMemoryRef::num_initialized] are actually initialized.
+    pub unsafe fn assume_init_spare(&self, n: usize) -> Self {
+        let num_initialized = self.num_initialized + n;
+        assert!(
+            num_initialized <= self.capacity,
+            "cannot assume more bytes initialized than the allocated capacity"
+        );
+
+        Self {
+            inner: self.inner,
+            offset: self.offset,
+            length: self.length,
+            capacity: self.capacity,
+            num_initialized,
+            phantom: std::marker::PhantomData,
+        }
     }
 
     /// Returns a slice of bytes of the referenced data. The bytes should
@@ -167,7 +396,7 @@ impl<'a> RawMemoryRef<'a> {
     /// assert_eq!(bytes.len(), 8);
     /// ```
     pub fn initialized_bytes(&self) -> &'a [u8] {
-        unsafe { std::slice::from_raw_parts(self.inner as *const u8, self.length) }
+        unsafe { std::slice::from_raw_parts(self.byte_ptr(), self.length) }
     }
 
     /// Number of bytes allocated that should be initialized of the referenced data.
@@ -179,6 +408,383 @@ impl<'a> RawMemoryRef<'a> {
     pub fn capacity(&self) -> usize {
         self.capacity
     }
+
+    /// Number of bytes, starting from the beginning of the referenced data,
+    /// that are known to be initialized. Always between [`len`][RawMemoryRef::len]
+    /// and [`capacity`][RawMemoryRef::capacity], inclusive.
+    pub fn num_initialized(&self) -> usize {
+        self.num_initialized
+    }
+
+    /// Returns a new [`RawMemoryRef`] viewing `range` of this view's bytes,
+    /// sharing the same base allocation and lifetime `'a`.
+    ///
+    /// The range is validated against `[0, capacity())`, so it may reach into
+    /// the spare-capacity tail as well as the initialized region; the
+    /// resulting view's [`len`][RawMemoryRef::len] and
+    /// [`num_initialized`][RawMemoryRef::num_initialized] are narrowed
+    /// accordingly, preserving `len() <= num_initialized() <= capacity()`.
+    /// Panics if the range is out of bounds.
+    /// ```
+    /// let value = vec![1u8, 2, 3, 4, 5];
+    /// let dataref = unsafe {
+    ///     raw_memory_ref::RawMemoryRef::new(&value)
+    ///         .into_inner_with_length_and_capacity(std::mem::size_of::<u8>())
+    /// };
+    ///
+    /// assert_eq!(dataref.slice(1..3).initialized_bytes(), [2u8, 3]);
+    /// ```
+    pub fn slice(&self, range: impl std::ops::RangeBounds<usize>) -> RawMemoryRef<'a> {
+        let start = match range.start_bound() {
+            std::ops::Bound::Included(&n) => n,
+            std::ops::Bound::Excluded(&n) => n + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            std::ops::Bound::Included(&n) => n + 1,
+            std::ops::Bound::Excluded(&n) => n,
+            std::ops::Bound::Unbounded => self.capacity,
+        };
+
+        assert!(
+            start <= end && end <= self.capacity,
+            "slice range [{start}, {end}) out of bounds for capacity {}",
+            self.capacity
+        );
+
+        let capacity = end - start;
+        let num_initialized = self.num_initialized.saturating_sub(start).min(capacity);
+        let length = self.length.saturating_sub(start).min(num_initialized);
+
+        Self {
+            inner: self.inner,
+            offset: self.offset + start,
+            length,
+            capacity,
+            num_initialized,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Splits off a view of `[0, at)`, leaving `[at, len())` viewable through
+    /// [`split_off`][RawMemoryRef::split_off]. Does not consume or mutate
+    /// `self`, consistent with the rest of this type's builder-style methods.
+    /// Panics if `at` is out of bounds.
+    pub fn split_to(&self, at: usize) -> RawMemoryRef<'a> {
+        self.slice(0..at)
+    }
+
+    /// Splits off a view of `[at, len())`, the complement of
+    /// [`split_to`][RawMemoryRef::split_to]. Panics if `at` is out of bounds.
+    pub fn split_off(&self, at: usize) -> RawMemoryRef<'a> {
+        self.slice(at..self.length)
+    }
+
+    /// Copies [`initialized_bytes`][RawMemoryRef::initialized_bytes] into an
+    /// independently-owned [`OwnedMemoryBuf`] that survives the referent
+    /// being dropped.
+    ///
+    /// This is the safe escape hatch out of this type's core footgun: once
+    /// the original value behind a `RawMemoryRef` is dropped, reading through
+    /// the reference is use-after-free. Call `to_owned` at a known-valid
+    /// point to take a snapshot that can outlive the source or be sent across
+    /// threads.
+    /// ```
+    /// let value = vec![1u8, 2, 3];
+    /// let dataref = unsafe {
+    ///     raw_memory_ref::RawMemoryRef::new(&value)
+    ///         .into_inner_with_length_and_capacity(std::mem::size_of::<u8>())
+    /// };
+    /// let owned = dataref.to_owned();
+    /// drop(value);
+    ///
+    /// assert_eq!(owned.as_bytes(), [1u8, 2, 3]);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn to_owned(&self) -> OwnedMemoryBuf {
+        OwnedMemoryBuf {
+            bytes: std::sync::Arc::from(self.initialized_bytes()),
+            offset: 0,
+            length: self.length,
+        }
+    }
+
+    /// Copies [`initialized_bytes`][RawMemoryRef::initialized_bytes] into a
+    /// freshly allocated [`AlignedBytes`] buffer whose start address is a
+    /// multiple of `align` bytes, padding the tail with zeros up to the next
+    /// multiple of `align`.
+    ///
+    /// A common choice is [`CACHE_LINE_ALIGN`] (64 bytes), matching the
+    /// alignment guaranteed by Arrow's `MutableBuffer`. This lets raw bytes
+    /// read from an arbitrary referent be handed directly to SIMD/columnar
+    /// code that requires aligned input, without the caller re-copying.
+    ///
+    /// This can't return a plain [`Vec<u8>`]: `Vec`'s allocator contract
+    /// requires its backing memory to have been allocated with `align = 1`
+    /// (`Layout::array::<u8>`), so wrapping an over-aligned allocation in a
+    /// `Vec` would deallocate it with the wrong layout on drop — undefined
+    /// behavior. [`AlignedBytes`] instead remembers the real layout it was
+    /// allocated with.
+    /// ```
+    /// let value = [1u8, 2, 3];
+    /// let dataref = raw_memory_ref::RawMemoryRef::new(&value);
+    /// let aligned = dataref.to_aligned_vec(raw_memory_ref::CACHE_LINE_ALIGN);
+    ///
+    /// assert_eq!(aligned.as_bytes().as_ptr() as usize % raw_memory_ref::CACHE_LINE_ALIGN, 0);
+    /// assert_eq!(&aligned.as_bytes()[..3], [1u8, 2, 3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two, or if the rounded-up size
+    /// would overflow `isize::MAX`. An empty view returns an empty
+    /// [`AlignedBytes`] without checking `align`.
+    pub fn to_aligned_vec(&self, align: usize) -> AlignedBytes {
+        let bytes = self.initialized_bytes();
+        if bytes.is_empty() {
+            return AlignedBytes::empty();
+        }
+
+        let aligned_len = bytes.len().div_ceil(align) * align;
+        let layout = std::alloc::Layout::from_size_align(aligned_len, align)
+            .expect("align must be a power of two and the rounded size must not overflow");
+
+        unsafe {
+            let ptr = std::alloc::alloc_zeroed(layout);
+            if ptr.is_null() {
+                std::alloc::handle_alloc_error(layout);
+            }
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, bytes.len());
+            AlignedBytes { ptr, layout }
+        }
+    }
+
+    /// Reports whether this view's underlying pointer is already a multiple
+    /// of `align` bytes. [`to_aligned_vec`][RawMemoryRef::to_aligned_vec]
+    /// allocates and copies whatever this returns.
+    pub fn is_aligned(&self, align: usize) -> bool {
+        (self.byte_ptr() as usize).is_multiple_of(align)
+    }
+
+    /// Returns a stateful cursor over [`initialized_bytes`][RawMemoryRef::initialized_bytes],
+    /// which implements [`std::io::Read`] and `Iterator<Item = u8>`.
+    ///
+    /// This lets the raw bytes of an arbitrary referent be streamed into any
+    /// `Read`-consuming API (hashers, serializers, `io::copy`) without first
+    /// materializing a `Vec<u8>`.
+    /// ```
+    /// use std::io::Read;
+    ///
+    /// let value = [1u8, 2, 3];
+    /// let mut reader = raw_memory_ref::RawMemoryRef::new(&value).reader();
+    /// let mut buf = Vec::new();
+    /// reader.read_to_end(&mut buf).unwrap();
+    ///
+    /// assert_eq!(buf, [1u8, 2, 3]);
+    /// ```
+    pub fn reader(&self) -> RawMemoryReader<'a> {
+        RawMemoryReader {
+            bytes: self.initialized_bytes(),
+            pos: 0,
+        }
+    }
+
+    /// Byte pointer to the start of this view's window, accounting for `offset`.
+    fn byte_ptr(&self) -> *const u8 {
+        unsafe { (self.inner as *const u8).add(self.offset) }
+    }
+}
+
+/// An owned, over-aligned byte buffer returned by
+/// [`to_aligned_vec`][RawMemoryRef::to_aligned_vec].
+///
+/// Frees its memory with the same [`Layout`][std::alloc::Layout] it was
+/// allocated with, which a `Vec<u8>` cannot do for an alignment greater
+/// than one.
+pub struct AlignedBytes {
+    ptr: *mut u8,
+    layout: std::alloc::Layout,
+}
+
+impl AlignedBytes {
+    fn empty() -> Self {
+        Self {
+            ptr: std::ptr::NonNull::dangling().as_ptr(),
+            layout: std::alloc::Layout::from_size_align(0, 1).unwrap(),
+        }
+    }
+
+    /// Returns the aligned bytes as a slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        if self.layout.size() == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(self.ptr, self.layout.size()) }
+        }
+    }
+
+    /// Number of bytes held by this buffer, including any alignment padding.
+    pub fn len(&self) -> usize {
+        self.layout.size()
+    }
+
+    /// Whether this buffer holds zero bytes.
+    pub fn is_empty(&self) -> bool {
+        self.layout.size() == 0
+    }
+}
+
+impl Drop for AlignedBytes {
+    fn drop(&mut self) {
+        if self.layout.size() != 0 {
+            unsafe { std::alloc::dealloc(self.ptr, self.layout) }
+        }
+    }
+}
+
+/// An owned, independently-lived snapshot of bytes copied out of a
+/// [`RawMemoryRef`] via [`to_owned`][RawMemoryRef::to_owned].
+///
+/// Backed by an [`Arc<[u8]>`][std::sync::Arc], so cloning is a cheap refcount
+/// bump and the bytes can be shared across threads without re-copying.
+#[derive(Clone)]
+pub struct OwnedMemoryBuf {
+    bytes: std::sync::Arc<[u8]>,
+    offset: usize,
+    length: usize,
+}
+
+impl OwnedMemoryBuf {
+    /// Returns the owned bytes as a slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[self.offset..self.offset + self.length]
+    }
+
+    /// Number of bytes held by this buffer.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Whether this buffer holds zero bytes.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns a new [`OwnedMemoryBuf`] viewing `range` of this buffer's
+    /// bytes, sharing the same backing allocation. Panics if the range is
+    /// out of bounds.
+    pub fn slice(&self, range: impl std::ops::RangeBounds<usize>) -> Self {
+        let start = match range.start_bound() {
+            std::ops::Bound::Included(&n) => n,
+            std::ops::Bound::Excluded(&n) => n + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            std::ops::Bound::Included(&n) => n + 1,
+            std::ops::Bound::Excluded(&n) => n,
+            std::ops::Bound::Unbounded => self.length,
+        };
+
+        assert!(
+            start <= end && end <= self.length,
+            "slice range [{start}, {end}) out of bounds for length {}",
+            self.length
+        );
+
+        Self {
+            bytes: self.bytes.clone(),
+            offset: self.offset + start,
+            length: end - start,
+        }
+    }
+
+    /// Splits off a view of `[0, at)`, leaving `[at, len())` viewable through
+    /// [`split_off`][OwnedMemoryBuf::split_off]. Panics if `at` is out of bounds.
+    pub fn split_to(&self, at: usize) -> Self {
+        self.slice(0..at)
+    }
+
+    /// Splits off a view of `[at, len())`, the complement of
+    /// [`split_to`][OwnedMemoryBuf::split_to]. Panics if `at` is out of bounds.
+    pub fn split_off(&self, at: usize) -> Self {
+        self.slice(at..self.length)
+    }
+}
+
+/// A stateful cursor over a [`RawMemoryRef`]'s initialized bytes, created via
+/// [`RawMemoryRef::reader`]. Mirrors the `Buf`/cursor style in the `bytes`
+/// crate: implements [`std::io::Read`] and `Iterator<Item = u8>`, and
+/// supports reading in fixed-size [`chunks`][RawMemoryReader::chunks].
+pub struct RawMemoryReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> RawMemoryReader<'a> {
+    /// The unread remainder of this cursor's bytes.
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.bytes[self.pos..]
+    }
+
+    /// Returns an iterator yielding the remaining bytes in chunks of at most
+    /// `n` bytes each, advancing this cursor's position as chunks are
+    /// consumed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, since no advancing chunk size could ever
+    /// exhaust `remaining`.
+    pub fn chunks(&mut self, n: usize) -> Chunks<'a, '_> {
+        assert!(n > 0, "chunk size must be greater than zero");
+
+        Chunks {
+            reader: self,
+            chunk_len: n,
+        }
+    }
+}
+
+impl std::io::Read for RawMemoryReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let remaining = self.remaining();
+        let n = remaining.len().min(buf.len());
+        buf[..n].copy_from_slice(&remaining[..n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+impl Iterator for RawMemoryReader<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = *self.bytes.get(self.pos)?;
+        self.pos += 1;
+        Some(byte)
+    }
+}
+
+/// Iterator over the remaining bytes of a [`RawMemoryReader`] in fixed-size
+/// chunks, returned by [`RawMemoryReader::chunks`].
+pub struct Chunks<'a, 'r> {
+    reader: &'r mut RawMemoryReader<'a>,
+    chunk_len: usize,
+}
+
+impl<'a> Iterator for Chunks<'a, '_> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        let remaining = self.reader.remaining();
+        if remaining.is_empty() {
+            return None;
+        }
+
+        let n = remaining.len().min(self.chunk_len);
+        let chunk = &remaining[..n];
+        self.reader.pos += n;
+        Some(chunk)
+    }
 }
 
 #[cfg(test)]
@@ -193,37 +799,39 @@ mod tests {
     #[test]
     fn new_unsafe_boxed_8bit_number() {
         let value = 5i8;
-        let bytes = RawMemoryRef::new(&value).allocated_bytes();
+        let bytes = unsafe { RawMemoryRef::new(&value).assume_all_init() };
         assert_eq!(bytes, [5u8]);
     }
 
     #[test]
     fn new_unsafe_boxed_8bit_negative_number() {
         let value = -5i8;
-        let bytes = RawMemoryRef::new(&value).allocated_bytes();
+        let bytes = unsafe { RawMemoryRef::new(&value).assume_all_init() };
         assert_eq!(bytes, [251u8]);
     }
 
     #[test]
     fn new_unsafe_boxed_8bit_number_slice() {
         let value = [1i8, 2, 3];
-        let bytes = RawMemoryRef::new(&value).allocated_bytes();
+        let bytes = unsafe { RawMemoryRef::new(&value).assume_all_init() };
         assert_eq!(bytes, [1u8, 2, 3]);
     }
 
     #[test]
     fn new_unsafe_boxed_16bit_number_slice() {
         let value = [1i16, 2, 3, i16::MAX];
-        let bytes = RawMemoryRef::new(&value).allocated_bytes();
+        let bytes = unsafe { RawMemoryRef::new(&value).assume_all_init() };
         assert_eq!(bytes, [1u8, 0, 2, 0, 3, 0, 255, 127]);
     }
 
     #[test]
     fn new_unsafe_boxed_8bit_number_vec() {
         let value = vec![1i8, 2, 3];
-        let bytes =
-            unsafe { RawMemoryRef::new(&value).into_inner_with_length(std::mem::size_of::<i8>()) }
-                .allocated_bytes();
+        let bytes = unsafe {
+            RawMemoryRef::new(&value)
+                .into_inner_with_length_and_capacity(std::mem::size_of::<i8>())
+                .assume_all_init()
+        };
         assert_eq!(bytes, [1u8, 2, 3]);
     }
 
@@ -237,9 +845,10 @@ mod tests {
             vec
         };
         let bytes = unsafe {
-            RawMemoryRef::new(&value).into_inner_with_length_and_capacity(std::mem::size_of::<i8>())
-        }
-        .allocated_bytes();
+            RawMemoryRef::new(&value)
+                .into_inner_with_length_and_capacity(std::mem::size_of::<i8>())
+                .assume_all_init()
+        };
         assert_eq!(bytes[0], 1);
         assert_eq!(bytes[1], 2);
         assert_eq!(bytes[2], 3);
@@ -249,9 +858,11 @@ mod tests {
     #[test]
     fn new_unsafe_boxed_string_slice() {
         let value = "abc";
-        let bytes =
-            unsafe { RawMemoryRef::new(&value).into_inner_with_length(std::mem::size_of::<u8>()) }
-                .allocated_bytes();
+        let bytes = unsafe {
+            RawMemoryRef::new(&value)
+                .into_inner_with_length(std::mem::size_of::<u8>())
+                .assume_all_init()
+        };
         assert_eq!(bytes, ['a' as u8, 'b' as u8, 'c' as u8]);
     }
 
@@ -265,12 +876,219 @@ mod tests {
             s
         };
         let bytes = unsafe {
-            RawMemoryRef::new(&value).into_inner_with_length_and_capacity(std::mem::size_of::<u8>())
-        }
-        .allocated_bytes();
+            RawMemoryRef::new(&value)
+                .into_inner_with_length_and_capacity(std::mem::size_of::<u8>())
+                .assume_all_init()
+        };
         assert_eq!(bytes[0], 'a' as u8);
         assert_eq!(bytes[1], 'b' as u8);
         assert_eq!(bytes[2], 'c' as u8);
         assert_eq!(bytes.len(), 5);
     }
+
+    #[test]
+    fn spare_capacity_covers_uninitialized_tail() {
+        let value = {
+            let mut vec = Vec::with_capacity(5);
+            vec.push(1i8);
+            vec.push(2);
+            vec.push(3);
+            vec
+        };
+        let dataref = unsafe {
+            RawMemoryRef::new(&value).into_inner_with_length_and_capacity(std::mem::size_of::<i8>())
+        };
+
+        assert_eq!(dataref.len(), 3);
+        assert_eq!(dataref.num_initialized(), 3);
+        assert_eq!(dataref.capacity(), 5);
+        assert_eq!(dataref.spare_capacity().len(), 2);
+    }
+
+    #[test]
+    fn assume_init_spare_advances_num_initialized() {
+        let value = {
+            let mut vec = Vec::with_capacity(5);
+            vec.push(1i8);
+            vec.push(2);
+            vec.push(3);
+            vec
+        };
+        let dataref = unsafe {
+            RawMemoryRef::new(&value).into_inner_with_length_and_capacity(std::mem::size_of::<i8>())
+        };
+
+        // Guards against reading the wrong word as `capacity` (e.g. picking
+        // up `Vec`'s pointer or length instead), which would otherwise make
+        // every assertion below pass or fail for the wrong reason.
+        assert_eq!(dataref.capacity(), 5);
+
+        let widened = unsafe { dataref.assume_init_spare(2) };
+
+        assert_eq!(widened.num_initialized(), 5);
+        assert_eq!(widened.spare_capacity().len(), 0);
+    }
+
+    #[test]
+    fn slice_views_a_sub_range() {
+        let value = [1u8, 2, 3, 4, 5];
+        let dataref = RawMemoryRef::new(&value);
+
+        assert_eq!(dataref.slice(1..3).initialized_bytes(), [2u8, 3]);
+        assert_eq!(dataref.slice(..2).initialized_bytes(), [1u8, 2]);
+        assert_eq!(dataref.slice(3..).initialized_bytes(), [4u8, 5]);
+    }
+
+    #[test]
+    fn split_to_and_split_off_view_complementary_ranges() {
+        let value = [1u8, 2, 3, 4, 5];
+        let dataref = RawMemoryRef::new(&value);
+
+        assert_eq!(dataref.split_to(2).initialized_bytes(), [1u8, 2]);
+        assert_eq!(dataref.split_off(2).initialized_bytes(), [3u8, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn slice_out_of_bounds_panics() {
+        let value = [1u8, 2, 3];
+        RawMemoryRef::new(&value).slice(0..10);
+    }
+
+    #[test]
+    fn slice_into_spare_capacity_keeps_len_initialized_invariant() {
+        let value = [1u8, 2, 3];
+        let dataref = unsafe { RawMemoryRef::with_capacity(&value, 10) };
+
+        let spare = dataref.slice(0..10);
+
+        assert!(spare.len() <= spare.num_initialized());
+        assert!(spare.num_initialized() <= spare.capacity());
+        assert_eq!(spare.initialized_bytes(), [1u8, 2, 3]);
+    }
+
+    #[test]
+    fn to_owned_survives_source_drop() {
+        let value = vec![1u8, 2, 3];
+        let dataref = unsafe {
+            RawMemoryRef::new(&value).into_inner_with_length_and_capacity(std::mem::size_of::<u8>())
+        };
+        let owned = dataref.to_owned();
+        drop(value);
+
+        assert_eq!(owned.as_bytes(), [1u8, 2, 3]);
+        assert_eq!(owned.len(), 3);
+    }
+
+    #[test]
+    fn owned_memory_buf_clone_is_cheap_and_shares_bytes() {
+        let value = [1u8, 2, 3];
+        let owned = RawMemoryRef::new(&value).to_owned();
+        let cloned = owned.clone();
+
+        assert_eq!(owned.as_bytes(), cloned.as_bytes());
+    }
+
+    #[test]
+    fn owned_memory_buf_slice_and_split() {
+        let value = [1u8, 2, 3, 4, 5];
+        let owned = RawMemoryRef::new(&value).to_owned();
+
+        assert_eq!(owned.slice(1..3).as_bytes(), [2u8, 3]);
+        assert_eq!(owned.split_to(2).as_bytes(), [1u8, 2]);
+        assert_eq!(owned.split_off(2).as_bytes(), [3u8, 4, 5]);
+    }
+
+    #[test]
+    fn follow_matches_into_inner_with_length() {
+        // A boxed slice is a genuine two-word (pointer, length) value with no
+        // separate capacity, unlike `Vec` whose word order isn't guaranteed.
+        let value: Box<[u8]> = vec![1u8, 2, 3].into_boxed_slice();
+        let via_into_inner = unsafe {
+            RawMemoryRef::new(&value).into_inner_with_length(std::mem::size_of::<u8>())
+        };
+        let via_follow = unsafe {
+            RawMemoryRef::new(&value).follow(
+                &[super::Step::LenFromWord(1), super::Step::Deref],
+                std::mem::size_of::<u8>(),
+            )
+        };
+
+        assert_eq!(
+            via_into_inner.initialized_bytes(),
+            via_follow.initialized_bytes()
+        );
+    }
+
+    #[test]
+    fn follow_steps_through_a_boxed_vec() {
+        // `follow` unboxes with a plain `Step::Deref`, then
+        // `into_inner_with_length_and_capacity` reads the inner `Vec`'s
+        // pointer/capacity/length words, whose order isn't hardcoded.
+        let value = Box::new(vec![1u8, 2, 3]);
+        let boxed = unsafe { RawMemoryRef::new(&value).follow(&[super::Step::Deref], 1) };
+        let bytes = unsafe { boxed.into_inner_with_length_and_capacity(std::mem::size_of::<u8>()) }
+            .initialized_bytes();
+
+        assert_eq!(bytes, [1u8, 2, 3]);
+    }
+
+    #[test]
+    fn to_aligned_vec_is_aligned_and_preserves_bytes() {
+        let value = [1u8, 2, 3];
+        let dataref = RawMemoryRef::new(&value);
+        let aligned = dataref.to_aligned_vec(super::CACHE_LINE_ALIGN);
+
+        assert_eq!(aligned.as_bytes().as_ptr() as usize % super::CACHE_LINE_ALIGN, 0);
+        assert_eq!(aligned.len() % super::CACHE_LINE_ALIGN, 0);
+        assert_eq!(&aligned.as_bytes()[..3], [1u8, 2, 3]);
+    }
+
+    #[test]
+    fn to_aligned_vec_of_empty_view_is_empty() {
+        let value: [u8; 0] = [];
+        let aligned = RawMemoryRef::new(&value).to_aligned_vec(super::CACHE_LINE_ALIGN);
+
+        assert!(aligned.is_empty());
+    }
+
+    #[test]
+    fn reader_implements_read() {
+        use std::io::Read;
+
+        let value = [1u8, 2, 3, 4, 5];
+        let mut reader = RawMemoryRef::new(&value).reader();
+        let mut buf = [0u8; 3];
+
+        assert_eq!(reader.read(&mut buf).unwrap(), 3);
+        assert_eq!(buf, [1u8, 2, 3]);
+        assert_eq!(reader.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf[..2], [4u8, 5]);
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn reader_implements_iterator() {
+        let value = [1u8, 2, 3];
+        let reader = RawMemoryRef::new(&value).reader();
+
+        assert_eq!(reader.collect::<Vec<u8>>(), vec![1u8, 2, 3]);
+    }
+
+    #[test]
+    fn reader_chunks_splits_into_fixed_size_pieces() {
+        let value = [1u8, 2, 3, 4, 5];
+        let mut reader = RawMemoryRef::new(&value).reader();
+        let chunks: Vec<&[u8]> = reader.chunks(2).collect();
+
+        assert_eq!(chunks, vec![&[1u8, 2][..], &[3u8, 4][..], &[5u8][..]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be greater than zero")]
+    fn reader_chunks_of_zero_panics_instead_of_looping_forever() {
+        let value = [1u8, 2, 3];
+        let mut reader = RawMemoryRef::new(&value).reader();
+        reader.chunks(0);
+    }
 }